@@ -77,6 +77,145 @@ fn fs_main(input: FragmentInput) -> @location(0) vec4<f32> {
 }
 "#;
 
+/// 지오메트리 디버깅용 폴리곤 렌더링 모드
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PolygonMode {
+    /// 면을 채워서 그림 (기본값, 컬링 없음)
+    Filled,
+    /// 외곽선만 그림 (`Features::POLYGON_MODE_LINE` 필요)
+    Wireframe,
+    /// 면을 채우고 뒷면은 컬링
+    BackFaceCulled,
+}
+
+impl PolygonMode {
+    /// 주어진 device 기능으로 실제 사용할 모드를 결정
+    ///
+    /// 와이어프레임을 지원하지 않으면 `Filled`로 대체
+    pub fn resolve(self, features: wgpu::Features) -> PolygonMode {
+        match self {
+            PolygonMode::Wireframe if !features.contains(wgpu::Features::POLYGON_MODE_LINE) => {
+                PolygonMode::Filled
+            }
+            mode => mode,
+        }
+    }
+
+    /// 디버그 토글용 다음 모드 (Filled → Wireframe → BackFaceCulled → Filled)
+    pub fn next(self) -> PolygonMode {
+        match self {
+            PolygonMode::Filled => PolygonMode::Wireframe,
+            PolygonMode::Wireframe => PolygonMode::BackFaceCulled,
+            PolygonMode::BackFaceCulled => PolygonMode::Filled,
+        }
+    }
+
+    fn primitive_state(self) -> wgpu::PrimitiveState {
+        match self {
+            PolygonMode::Filled => wgpu::PrimitiveState::default(),
+            PolygonMode::Wireframe => wgpu::PrimitiveState {
+                polygon_mode: wgpu::PolygonMode::Line,
+                ..Default::default()
+            },
+            PolygonMode::BackFaceCulled => wgpu::PrimitiveState {
+                cull_mode: Some(wgpu::Face::Back),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// `Renderer::render_timed`가 반환하는 프레임 단계별 소요 시간
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct FrameTimings {
+    /// 커맨드 인코딩에 걸린 CPU 시간
+    pub encode: std::time::Duration,
+    /// `queue.submit`에 걸린 CPU 시간
+    pub submit: std::time::Duration,
+    /// 렌더 패스의 GPU 시간 (device가 `TIMESTAMP_QUERY`를 지원하지 않으면 `None`)
+    pub gpu: Option<std::time::Duration>,
+}
+
+/// 렌더 패스 시작/끝 타임스탬프를 기록하고 읽어오기 위한 쿼리 리소스
+struct TimestampQuery {
+    query_set: wgpu::QuerySet,
+    resolve_buffer: wgpu::Buffer,
+    readback_buffer: wgpu::Buffer,
+}
+
+impl TimestampQuery {
+    const COUNT: u32 = 2;
+    const SIZE: wgpu::BufferAddress = Self::COUNT as wgpu::BufferAddress * 8;
+
+    /// device가 `TIMESTAMP_QUERY`를 지원할 때만 생성
+    fn new(device: &wgpu::Device) -> Option<Self> {
+        if !device.features().contains(wgpu::Features::TIMESTAMP_QUERY) {
+            return None;
+        }
+
+        Some(Self {
+            query_set: device.create_query_set(&wgpu::QuerySetDescriptor {
+                label: Some("Timestamp Query Set"),
+                ty: wgpu::QueryType::Timestamp,
+                count: Self::COUNT,
+            }),
+            resolve_buffer: device.create_buffer(&wgpu::BufferDescriptor {
+                label: Some("Timestamp Resolve Buffer"),
+                size: Self::SIZE,
+                usage: wgpu::BufferUsages::QUERY_RESOLVE | wgpu::BufferUsages::COPY_SRC,
+                mapped_at_creation: false,
+            }),
+            readback_buffer: device.create_buffer(&wgpu::BufferDescriptor {
+                label: Some("Timestamp Readback Buffer"),
+                size: Self::SIZE,
+                usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+                mapped_at_creation: false,
+            }),
+        })
+    }
+
+    fn timestamp_writes(&self) -> wgpu::RenderPassTimestampWrites<'_> {
+        wgpu::RenderPassTimestampWrites {
+            query_set: &self.query_set,
+            beginning_of_pass_write_index: Some(0),
+            end_of_pass_write_index: Some(1),
+        }
+    }
+
+    /// 기록된 타임스탬프를 읽기용 버퍼로 복사하는 커맨드 추가
+    fn resolve(&self, encoder: &mut wgpu::CommandEncoder) {
+        encoder.resolve_query_set(&self.query_set, 0..Self::COUNT, &self.resolve_buffer, 0);
+        encoder.copy_buffer_to_buffer(
+            &self.resolve_buffer,
+            0,
+            &self.readback_buffer,
+            0,
+            Self::SIZE,
+        );
+    }
+
+    /// 제출된 프레임이 끝날 때까지 기다린 뒤 패스의 GPU 시간을 읽어옴
+    fn read(&self, device: &wgpu::Device, queue: &wgpu::Queue) -> Option<std::time::Duration> {
+        let slice = self.readback_buffer.slice(..);
+        let (sender, receiver) = std::sync::mpsc::channel();
+        slice.map_async(wgpu::MapMode::Read, move |result| {
+            let _ = sender.send(result);
+        });
+        device.poll(wgpu::Maintain::Wait);
+        receiver.recv().ok()?.ok()?;
+
+        let ticks = {
+            let data = slice.get_mapped_range();
+            let timestamps: &[u64] = bytemuck::cast_slice(&data);
+            timestamps[1].saturating_sub(timestamps[0])
+        };
+        self.readback_buffer.unmap();
+
+        let nanos = ticks as f64 * queue.get_timestamp_period() as f64;
+        Some(std::time::Duration::from_nanos(nanos as u64))
+    }
+}
+
 pub struct Renderer<'a> {
     pub device: wgpu::Device,
     pub queue: wgpu::Queue,
@@ -84,61 +223,127 @@ pub struct Renderer<'a> {
     pub config: wgpu::SurfaceConfiguration,
     pub render_pipeline: wgpu::RenderPipeline,
     pub vertex_buffer: wgpu::Buffer,
+    shader_module: wgpu::ShaderModule,
+    pipeline_layout: wgpu::PipelineLayout,
+    polygon_mode: PolygonMode,
+    /// 헤드리스 모드에서 surface 대신 그리는 텍스처
+    offscreen_texture: Option<wgpu::Texture>,
+    adapter_info: wgpu::AdapterInfo,
+    /// `render_timed`에서 GPU 시간을 재기 위한 쿼리 (지원하지 않으면 `None`)
+    timestamp_query: Option<TimestampQuery>,
 }
 
 impl<'a> Renderer<'a> {
     pub fn render(&mut self) -> Result<(), wgpu::SurfaceError> {
-        if let Some(surface) = &self.surface {
-            let output = surface.get_current_texture()?;
-            let view = output
-                .texture
-                .create_view(&wgpu::TextureViewDescriptor::default());
-
-            let mut encoder = self
-                .device
-                .create_command_encoder(&wgpu::CommandEncoderDescriptor {
-                    label: Some("Render Encoder"),
-                });
+        self.render_frame(None)
+    }
 
-            {
-                let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
-                    label: Some("Render Pass"),
-                    color_attachments: &[Some(wgpu::RenderPassColorAttachment {
-                        view: &view,
-                        resolve_target: None,
-                        ops: wgpu::Operations {
-                            load: wgpu::LoadOp::Clear(wgpu::Color {
-                                r: 0.1,
-                                g: 0.2,
-                                b: 0.3,
-                                a: 1.0,
-                            }),
-                            store: wgpu::StoreOp::Store,
-                        },
-                    })],
-                    depth_stencil_attachment: None,
-                    occlusion_query_set: None,
-                    timestamp_writes: None,
-                });
-
-                render_pass.set_pipeline(&self.render_pipeline);
-                render_pass.set_vertex_buffer(0, self.vertex_buffer.slice(..));
-                render_pass.draw(0..3, 0..1);
-            }
+    /// 한 프레임을 렌더링하고 단계별 소요 시간을 반환
+    ///
+    /// 헤드리스 모드에서는 오프스크린 텍스처에 그려서 측정함
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn render_timed(&mut self) -> Result<FrameTimings, wgpu::SurfaceError> {
+        let mut timings = FrameTimings::default();
+        self.render_frame(Some(&mut timings))?;
+        Ok(timings)
+    }
 
-            self.queue.submit(std::iter::once(encoder.finish()));
+    /// surface(헤드리스 모드에서는 오프스크린 텍스처)에 한 프레임을 그림
+    ///
+    /// `timings`가 주어지면 인코딩/제출에 걸린 시간과 (지원되면) GPU 시간을 기록
+    fn render_frame(
+        &self,
+        mut timings: Option<&mut FrameTimings>,
+    ) -> Result<(), wgpu::SurfaceError> {
+        use std::time::Instant;
+
+        let output = match &self.surface {
+            Some(surface) => Some(surface.get_current_texture()?),
+            None => None,
+        };
+        let texture = match (&output, &self.offscreen_texture) {
+            (Some(output), _) => &output.texture,
+            (None, Some(texture)) => texture,
+            (None, None) => return Ok(()),
+        };
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+        let timestamp_query = self.timestamp_query.as_ref().filter(|_| timings.is_some());
+
+        let encode_start = timings.is_some().then(Instant::now);
+        let command_buffer = self.encode_frame(&view, timestamp_query);
+        if let (Some(timings), Some(start)) = (timings.as_deref_mut(), encode_start) {
+            timings.encode = start.elapsed();
+        }
+
+        let submit_start = timings.is_some().then(Instant::now);
+        self.queue.submit(std::iter::once(command_buffer));
+        if let (Some(timings), Some(start)) = (timings.as_deref_mut(), submit_start) {
+            timings.submit = start.elapsed();
+        }
+        if let (Some(timings), Some(query)) = (timings, timestamp_query) {
+            timings.gpu = query.read(&self.device, &self.queue);
+        }
+
+        if let Some(output) = output {
             output.present();
         }
 
         Ok(())
     }
 
+    fn encode_frame(
+        &self,
+        view: &wgpu::TextureView,
+        timestamp_query: Option<&TimestampQuery>,
+    ) -> wgpu::CommandBuffer {
+        let mut encoder = self
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                label: Some("Render Encoder"),
+            });
+
+        {
+            let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("Render Pass"),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view,
+                    resolve_target: None,
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(wgpu::Color {
+                            r: 0.1,
+                            g: 0.2,
+                            b: 0.3,
+                            a: 1.0,
+                        }),
+                        store: wgpu::StoreOp::Store,
+                    },
+                })],
+                depth_stencil_attachment: None,
+                occlusion_query_set: None,
+                timestamp_writes: timestamp_query.map(TimestampQuery::timestamp_writes),
+            });
+
+            render_pass.set_pipeline(&self.render_pipeline);
+            render_pass.set_vertex_buffer(0, self.vertex_buffer.slice(..));
+            render_pass.draw(0..3, 0..1);
+        }
+
+        if let Some(query) = timestamp_query {
+            query.resolve(&mut encoder);
+        }
+
+        encoder.finish()
+    }
+
     pub fn resize(&mut self, new_size: winit::dpi::PhysicalSize<u32>) {
         if new_size.width > 0 && new_size.height > 0 {
             self.config.width = new_size.width;
             self.config.height = new_size.height;
             if let Some(surface) = &self.surface {
                 surface.configure(&self.device, &self.config);
+            } else {
+                self.offscreen_texture = Some(create_offscreen_texture(&self.device, &self.config));
             }
         }
     }
@@ -146,6 +351,40 @@ impl<'a> Renderer<'a> {
     pub fn size(&self) -> winit::dpi::PhysicalSize<u32> {
         winit::dpi::PhysicalSize::new(self.config.width, self.config.height)
     }
+
+    /// 폴리곤 모드를 바꾸고 파이프라인을 다시 생성
+    ///
+    /// 와이어프레임을 지원하지 않는 device에서는 `Filled`로 대체되며,
+    /// 실제로 적용된 모드를 반환함
+    pub fn set_polygon_mode(&mut self, mode: PolygonMode) -> PolygonMode {
+        let resolved = mode.resolve(self.device.features());
+        if resolved != mode {
+            log::warn!("{mode:?} is not supported on this device, falling back to {resolved:?}");
+        }
+
+        if resolved != self.polygon_mode {
+            self.render_pipeline = create_render_pipeline(
+                &self.device,
+                &self.pipeline_layout,
+                &self.shader_module,
+                self.config.format,
+                resolved,
+            );
+            self.polygon_mode = resolved;
+        }
+
+        resolved
+    }
+
+    /// Renderer가 사용 중인 어댑터 정보
+    pub fn adapter_info(&self) -> &wgpu::AdapterInfo {
+        &self.adapter_info
+    }
+
+    /// 현재 적용된 폴리곤 모드
+    pub fn polygon_mode(&self) -> PolygonMode {
+        self.polygon_mode
+    }
 }
 
 pub async fn create_renderer<T: platform::SurfaceProvider>(
@@ -163,16 +402,62 @@ pub async fn create_renderer<T: platform::SurfaceProvider>(
         wgpu::Instance::default()
     };
 
-    let (surface, size) = target.create_surface(&instance)?;
+    create_renderer_with_adapter(&instance, target, None).await
+}
 
-    let adapter = instance
-        .request_adapter(&wgpu::RequestAdapterOptions {
-            compatible_surface: surface.as_ref(),
-            ..Default::default()
-        })
-        .await
-        .ok_or("Failed to find an appropriate adapter")?;
+/// 주어진 instance와 어댑터로 Renderer 생성
+///
+/// `adapter`는 같은 `instance`에서 얻은 것이어야 하며,
+/// `None`이면 surface와 호환되는 어댑터를 자동으로 선택함
+pub async fn create_renderer_with_adapter<T: platform::SurfaceProvider>(
+    instance: &wgpu::Instance,
+    target: &T,
+    adapter: Option<wgpu::Adapter>,
+) -> Result<Renderer<'static>, Box<dyn std::error::Error>> {
+    let (surface, size) = target.create_surface(instance)?;
+
+    let adapter = match adapter {
+        Some(adapter) => {
+            if let Some(surface) = &surface
+                && !adapter.is_surface_supported(surface)
+            {
+                return Err(format!(
+                    "Adapter {:?} cannot present to this surface",
+                    adapter.get_info().name
+                )
+                .into());
+            }
+            adapter
+        }
+        None => instance
+            .request_adapter(&wgpu::RequestAdapterOptions {
+                compatible_surface: surface.as_ref(),
+                ..Default::default()
+            })
+            .await
+            .ok_or("Failed to find an appropriate adapter")?,
+    };
 
+    build_renderer(adapter, surface, size).await
+}
+
+/// `enumerate_adapters`로 얻은 목록에서 `index`번째 어댑터를 꺼냄
+pub fn select_adapter(
+    mut adapters: Vec<wgpu::Adapter>,
+    index: usize,
+) -> Result<wgpu::Adapter, Box<dyn std::error::Error>> {
+    if index >= adapters.len() {
+        return Err(format!("No adapter at index {index} ({} available)", adapters.len()).into());
+    }
+    Ok(adapters.swap_remove(index))
+}
+
+/// 선택된 어댑터로 device, surface 설정, 파이프라인을 만들어 Renderer 구성
+async fn build_renderer(
+    adapter: wgpu::Adapter,
+    surface: Option<wgpu::Surface<'static>>,
+    size: winit::dpi::PhysicalSize<u32>,
+) -> Result<Renderer<'static>, Box<dyn std::error::Error>> {
     // WebGL에서는 간단한 device 요청 사용
     let (device, queue) = if cfg!(target_arch = "wasm32") {
         // WebGL은 compute shader를 지원하지 않으므로 더 제한적인 limits 사용
@@ -198,8 +483,18 @@ pub async fn create_renderer<T: platform::SurfaceProvider>(
             .await
             .map_err(|e| format!("Failed to create WebGL device: {:?}", e))?
     } else {
-        // 네이티브에서는 기본 설정 사용
-        adapter.request_device(&Default::default(), None).await?
+        // 네이티브에서는 기본 설정 사용 (와이어프레임 디버그/GPU 시간 측정용 기능은 지원될 때만 요청)
+        let required_features = adapter.features()
+            & (wgpu::Features::POLYGON_MODE_LINE | wgpu::Features::TIMESTAMP_QUERY);
+        adapter
+            .request_device(
+                &wgpu::DeviceDescriptor {
+                    required_features,
+                    ..Default::default()
+                },
+                None,
+            )
+            .await?
     };
 
     let format = if let Some(surface) = &surface {
@@ -222,6 +517,10 @@ pub async fn create_renderer<T: platform::SurfaceProvider>(
     if let Some(surface) = &surface {
         surface.configure(&device, &config);
     }
+    let offscreen_texture = surface
+        .is_none()
+        .then(|| create_offscreen_texture(&device, &config));
+    let timestamp_query = TimestampQuery::new(&device);
 
     let shader_module = device.create_shader_module(wgpu::ShaderModuleDescriptor {
         label: Some("Shader"),
@@ -240,33 +539,258 @@ pub async fn create_renderer<T: platform::SurfaceProvider>(
         push_constant_ranges: &[],
     });
 
-    let render_pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+    let render_pipeline = create_render_pipeline(
+        &device,
+        &pipeline_layout,
+        &shader_module,
+        config.format,
+        PolygonMode::Filled,
+    );
+
+    Ok(Renderer {
+        device,
+        queue,
+        surface,
+        config,
+        render_pipeline,
+        vertex_buffer,
+        shader_module,
+        pipeline_layout,
+        polygon_mode: PolygonMode::Filled,
+        offscreen_texture,
+        adapter_info: adapter.get_info(),
+        timestamp_query,
+    })
+}
+
+/// 헤드리스 모드용 렌더 타겟 텍스처 생성
+fn create_offscreen_texture(
+    device: &wgpu::Device,
+    config: &wgpu::SurfaceConfiguration,
+) -> wgpu::Texture {
+    device.create_texture(&wgpu::TextureDescriptor {
+        label: Some("Offscreen Texture"),
+        size: wgpu::Extent3d {
+            width: config.width,
+            height: config.height,
+            depth_or_array_layers: 1,
+        },
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format: config.format,
+        usage: config.usage,
+        view_formats: &[],
+    })
+}
+
+/// 주어진 폴리곤 모드로 삼각형 렌더 파이프라인 생성
+fn create_render_pipeline(
+    device: &wgpu::Device,
+    layout: &wgpu::PipelineLayout,
+    shader_module: &wgpu::ShaderModule,
+    format: wgpu::TextureFormat,
+    polygon_mode: PolygonMode,
+) -> wgpu::RenderPipeline {
+    device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
         label: Some("Render Pipeline"),
-        layout: Some(&pipeline_layout),
+        layout: Some(layout),
         vertex: wgpu::VertexState {
-            module: &shader_module,
+            module: shader_module,
             entry_point: "vs_main",
             buffers: &[Vertex::desc()],
             compilation_options: wgpu::PipelineCompilationOptions::default(),
         },
         fragment: Some(wgpu::FragmentState {
-            module: &shader_module,
+            module: shader_module,
             entry_point: "fs_main",
-            targets: &[Some(config.format.into())],
+            targets: &[Some(format.into())],
             compilation_options: wgpu::PipelineCompilationOptions::default(),
         }),
-        primitive: wgpu::PrimitiveState::default(),
+        primitive: polygon_mode.primitive_state(),
         depth_stencil: None,
         multisample: wgpu::MultisampleState::default(),
         multiview: None,
-    });
-
-    Ok(Renderer {
-        device,
-        queue,
-        surface,
-        config,
-        render_pipeline,
-        vertex_buffer,
     })
 }
+
+/// 테스트용 Renderer 생성 함수
+///
+/// wgpu 기본 핸들러도 검증(validation) 에러에서 panic하지만, 메시지를 고정해서
+/// 테스트가 `should_panic(expected = "wgpu validation error")`로 확인할 수 있게 함
+#[cfg(any(test, feature = "test-utils"))]
+pub async fn create_renderer_for_testing<T: platform::SurfaceProvider>(
+    target: &T,
+) -> Result<Renderer<'static>, Box<dyn std::error::Error>> {
+    let renderer = create_renderer(target).await?;
+    renderer.device.on_uncaptured_error(Box::new(|error| {
+        panic!("wgpu validation error: {error}");
+    }));
+    Ok(renderer)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use platform::HeadlessProvider;
+    use pollster::block_on;
+
+    #[test]
+    fn wireframe_falls_back_to_filled_without_line_mode() {
+        assert_eq!(
+            PolygonMode::Wireframe.resolve(wgpu::Features::empty()),
+            PolygonMode::Filled
+        );
+        assert_eq!(
+            PolygonMode::Wireframe.resolve(wgpu::Features::POLYGON_MODE_LINE),
+            PolygonMode::Wireframe
+        );
+    }
+
+    #[test]
+    fn filled_and_culled_resolve_unchanged() {
+        for features in [wgpu::Features::empty(), wgpu::Features::POLYGON_MODE_LINE] {
+            assert_eq!(PolygonMode::Filled.resolve(features), PolygonMode::Filled);
+            assert_eq!(
+                PolygonMode::BackFaceCulled.resolve(features),
+                PolygonMode::BackFaceCulled
+            );
+        }
+    }
+
+    #[test]
+    fn polygon_mode_primitive_state() {
+        let filled = PolygonMode::Filled.primitive_state();
+        assert_eq!(filled.polygon_mode, wgpu::PolygonMode::Fill);
+        assert_eq!(filled.cull_mode, None);
+
+        let wireframe = PolygonMode::Wireframe.primitive_state();
+        assert_eq!(wireframe.polygon_mode, wgpu::PolygonMode::Line);
+        assert_eq!(wireframe.cull_mode, None);
+
+        let culled = PolygonMode::BackFaceCulled.primitive_state();
+        assert_eq!(culled.polygon_mode, wgpu::PolygonMode::Fill);
+        assert_eq!(culled.cull_mode, Some(wgpu::Face::Back));
+    }
+
+    #[test]
+    fn polygon_mode_next_cycles_all_modes() {
+        let mut mode = PolygonMode::Filled;
+        for expected in [
+            PolygonMode::Wireframe,
+            PolygonMode::BackFaceCulled,
+            PolygonMode::Filled,
+        ] {
+            mode = mode.next();
+            assert_eq!(mode, expected);
+        }
+    }
+
+    fn headless_renderer() -> Renderer<'static> {
+        block_on(create_renderer_for_testing(&HEADLESS))
+            .expect("failed to create headless renderer")
+    }
+
+    const HEADLESS: HeadlessProvider = HeadlessProvider {
+        width: 64,
+        height: 64,
+    };
+
+    #[test]
+    fn explicit_adapter_is_used() {
+        let instance = wgpu::Instance::default();
+        let adapters = instance.enumerate_adapters(wgpu::Backends::all());
+        assert!(!adapters.is_empty(), "no adapters enumerated");
+
+        for adapter in adapters {
+            let info = adapter.get_info();
+            let renderer = block_on(create_renderer_with_adapter(
+                &instance,
+                &HEADLESS,
+                Some(adapter),
+            ))
+            .expect("failed to create renderer on selected adapter");
+            assert_eq!(renderer.adapter_info(), &info);
+        }
+    }
+
+    #[test]
+    fn select_adapter_respects_index() {
+        let instance = wgpu::Instance::default();
+        let adapters = instance.enumerate_adapters(wgpu::Backends::all());
+        let count = adapters.len();
+        assert!(count > 0, "no adapters enumerated");
+
+        let expected = adapters[count - 1].get_info();
+        let selected = select_adapter(adapters, count - 1).unwrap();
+        assert_eq!(selected.get_info(), expected);
+
+        let adapters = instance.enumerate_adapters(wgpu::Backends::all());
+        assert!(select_adapter(adapters, count).is_err());
+    }
+
+    #[test]
+    fn render_timed_measures_headless_frame() {
+        let mut renderer = headless_renderer();
+        let timings = renderer.render_timed().expect("headless render failed");
+
+        assert!(timings.encode > std::time::Duration::ZERO);
+        assert!(timings.submit > std::time::Duration::ZERO);
+
+        // GPU 시간은 타임스탬프 쿼리를 지원하는 device에서만 측정됨
+        let supports_timestamps = renderer
+            .device
+            .features()
+            .contains(wgpu::Features::TIMESTAMP_QUERY);
+        assert_eq!(timings.gpu.is_some(), supports_timestamps);
+    }
+
+    #[test]
+    #[should_panic(expected = "wgpu validation error")]
+    fn invalid_draw_triggers_error_handler() {
+        let renderer = headless_renderer();
+
+        // 파이프라인과 다른 포맷의 타겟에 그려서 검증 에러를 일으킴
+        let format = if renderer.config.format == wgpu::TextureFormat::Rgba8Unorm {
+            wgpu::TextureFormat::R8Unorm
+        } else {
+            wgpu::TextureFormat::Rgba8Unorm
+        };
+        let texture = renderer.device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("Mismatched Target"),
+            size: wgpu::Extent3d {
+                width: 64,
+                height: 64,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+            view_formats: &[],
+        });
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+        let mut encoder = renderer
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor { label: None });
+        {
+            let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: None,
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: &view,
+                    resolve_target: None,
+                    ops: wgpu::Operations::default(),
+                })],
+                depth_stencil_attachment: None,
+                occlusion_query_set: None,
+                timestamp_writes: None,
+            });
+            render_pass.set_pipeline(&renderer.render_pipeline);
+            render_pass.set_vertex_buffer(0, renderer.vertex_buffer.slice(..));
+            render_pass.draw(0..3, 0..1);
+        }
+        renderer.queue.submit(std::iter::once(encoder.finish()));
+    }
+}
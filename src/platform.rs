@@ -1,4 +1,6 @@
 use wgpu::{Instance, Surface};
+#[cfg(not(target_arch = "wasm32"))]
+use winit::dpi::PhysicalPosition;
 use winit::dpi::PhysicalSize;
 
 /// 플랫폼별 Surface 생성을 추상화하는 트레이트
@@ -19,42 +21,322 @@ impl SurfaceProvider for winit::window::Window {
         let surface = instance.create_surface(self)?;
         let size = self.inner_size();
         // Surface를 'static으로 변환하기 위해 unsafe 사용
-        let static_surface = unsafe { std::mem::transmute(surface) };
+        let static_surface =
+            unsafe { std::mem::transmute::<Surface<'_>, Surface<'static>>(surface) };
         Ok((Some(static_surface), size))
     }
 }
 
+/// 창 위치/크기를 저장하는 파일 이름 (실행 파일과 같은 디렉터리에 저장)
+#[cfg(not(target_arch = "wasm32"))]
+pub const WINDOW_STATE_FILE: &str = "window_state.txt";
+
+/// 창 상태 파일 경로 (실행 파일 위치를 알 수 없으면 `None`)
+#[cfg(not(target_arch = "wasm32"))]
+pub fn window_state_path() -> Option<std::path::PathBuf> {
+    Some(
+        std::env::current_exe()
+            .ok()?
+            .parent()?
+            .join(WINDOW_STATE_FILE),
+    )
+}
+
+/// 실행 간에 유지되는 창 위치/크기 정보
+///
+/// 위치/크기는 최대화되지 않은 일반 상태의 값이며, 최대화 여부는 따로 저장함
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct WindowGeometry {
+    /// 창 바깥쪽 좌상단 위치 (Wayland처럼 위치를 알 수 없으면 `None`)
+    pub position: Option<PhysicalPosition<i32>>,
+    pub size: PhysicalSize<u32>,
+    pub maximized: bool,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl WindowGeometry {
+    /// 모니터와 이만큼(픽셀) 이상 겹쳐야 화면에 보이는 것으로 판단
+    const MIN_VISIBLE: u32 = 64;
+
+    /// 현재 창 상태를 읽어옴
+    pub fn from_window(window: &winit::window::Window) -> Self {
+        Self {
+            position: window.outer_position().ok(),
+            size: window.inner_size(),
+            maximized: window.is_maximized(),
+        }
+    }
+
+    /// 현재 창 상태를 반영
+    ///
+    /// 최대화된 동안에는 최대화 여부만 갱신하고, 복원할 때 쓸 일반 위치/크기는 유지함
+    pub fn update_from_window(&mut self, window: &winit::window::Window) {
+        self.apply(
+            window.outer_position().ok(),
+            window.inner_size(),
+            window.is_maximized(),
+        );
+    }
+
+    fn apply(
+        &mut self,
+        position: Option<PhysicalPosition<i32>>,
+        size: PhysicalSize<u32>,
+        maximized: bool,
+    ) {
+        self.maximized = maximized;
+        if !maximized {
+            self.position = position;
+            self.size = size;
+        }
+    }
+
+    /// 파일에서 읽기 (파일이 없거나 형식이 잘못되면 `None`)
+    pub fn load(path: &std::path::Path) -> Option<Self> {
+        Self::parse(&std::fs::read_to_string(path).ok()?)
+    }
+
+    pub fn save(&self, path: &std::path::Path) -> std::io::Result<()> {
+        std::fs::write(path, self.serialize())
+    }
+
+    /// `key=value` 줄 단위 형식을 파싱
+    fn parse(text: &str) -> Option<Self> {
+        let mut x = None;
+        let mut y = None;
+        let mut width = None;
+        let mut height = None;
+        let mut maximized = false;
+
+        for line in text.lines() {
+            let Some((key, value)) = line.split_once('=') else {
+                continue;
+            };
+            let value = value.trim();
+            match key.trim() {
+                "x" => x = Some(value.parse().ok()?),
+                "y" => y = Some(value.parse().ok()?),
+                "width" => width = Some(value.parse().ok()?),
+                "height" => height = Some(value.parse().ok()?),
+                "maximized" => maximized = value.parse().ok()?,
+                _ => {}
+            }
+        }
+
+        let position = match (x, y) {
+            (Some(x), Some(y)) => Some(PhysicalPosition::new(x, y)),
+            _ => None,
+        };
+        let size = PhysicalSize::new(width?, height?);
+        if size.width == 0 || size.height == 0 {
+            return None;
+        }
+
+        Some(Self {
+            position,
+            size,
+            maximized,
+        })
+    }
+
+    fn serialize(&self) -> String {
+        let mut text = String::new();
+        if let Some(position) = self.position {
+            text += &format!("x={}\ny={}\n", position.x, position.y);
+        }
+        text += &format!(
+            "width={}\nheight={}\nmaximized={}\n",
+            self.size.width, self.size.height, self.maximized
+        );
+        text
+    }
+
+    /// 저장된 위치가 어느 모니터에도 충분히 보이지 않으면 첫 번째 모니터 가운데로 옮김
+    ///
+    /// `monitors`는 (위치, 크기) 목록이며 첫 번째 항목을 기본 모니터로 사용함.
+    /// 옮길 때는 창 크기도 모니터 크기를 넘지 않도록 줄임
+    pub fn clamp_to_monitors(
+        self,
+        monitors: &[(PhysicalPosition<i32>, PhysicalSize<u32>)],
+    ) -> Self {
+        let (Some(position), Some(&(monitor_position, monitor_size))) =
+            (self.position, monitors.first())
+        else {
+            return self;
+        };
+
+        let overlap = |start: i32, len: u32, other_start: i32, other_len: u32| {
+            let end = start as i64 + len as i64;
+            let other_end = other_start as i64 + other_len as i64;
+            (end.min(other_end) - (start as i64).max(other_start as i64)).max(0) as u32
+        };
+        let visible = monitors.iter().any(|&(monitor_position, monitor_size)| {
+            overlap(
+                position.x,
+                self.size.width,
+                monitor_position.x,
+                monitor_size.width,
+            ) >= Self::MIN_VISIBLE.min(self.size.width)
+                && overlap(
+                    position.y,
+                    self.size.height,
+                    monitor_position.y,
+                    monitor_size.height,
+                ) >= Self::MIN_VISIBLE.min(self.size.height)
+        });
+        if visible {
+            return self;
+        }
+
+        let size = PhysicalSize::new(
+            self.size.width.min(monitor_size.width),
+            self.size.height.min(monitor_size.height),
+        );
+        let position = PhysicalPosition::new(
+            monitor_position.x + ((monitor_size.width - size.width) / 2) as i32,
+            monitor_position.y + ((monitor_size.height - size.height) / 2) as i32,
+        );
+
+        Self {
+            position: Some(position),
+            size,
+            ..self
+        }
+    }
+}
+
+/// 사용할 어댑터 인덱스를 지정하는 환경 변수 (로그에 출력되는 순서 기준)
+#[cfg(not(target_arch = "wasm32"))]
+pub const ADAPTER_ENV: &str = "WGPU_TRIANGLE_ADAPTER";
+
 /// 네이티브 플랫폼 시작 함수
 #[cfg(not(target_arch = "wasm32"))]
 pub fn start() {
-    use crate::create_renderer;
+    use crate::{create_renderer_with_adapter, select_adapter};
     use pollster::block_on;
-    use winit::{event::*, event_loop::EventLoop, window::WindowBuilder};
+    use winit::{
+        event::*,
+        event_loop::EventLoop,
+        keyboard::{KeyCode, PhysicalKey},
+        window::WindowBuilder,
+    };
 
     env_logger::init();
 
+    // 어댑터 목록을 출력하고, 환경 변수로 지정된 어댑터가 있으면 선택
+    let instance = wgpu::Instance::default();
+    let adapters = instance.enumerate_adapters(wgpu::Backends::all());
+    for (index, adapter) in adapters.iter().enumerate() {
+        let info = adapter.get_info();
+        log::info!("Adapter {index}: {} ({:?})", info.name, info.backend);
+    }
+    let adapter = std::env::var(ADAPTER_ENV).ok().and_then(|value| {
+        value
+            .trim()
+            .parse()
+            .map_err(Box::<dyn std::error::Error>::from)
+            .and_then(|index| select_adapter(adapters, index))
+            .inspect_err(|e| log::warn!("Ignoring {ADAPTER_ENV}={value}: {e}"))
+            .ok()
+    });
+
     let event_loop = EventLoop::new().unwrap();
-    let window = WindowBuilder::new()
-        .with_title("WGPU Triangle")
-        .build(&event_loop)
-        .unwrap();
+    let state_path = window_state_path();
+    let mut builder = WindowBuilder::new().with_title("WGPU Triangle");
+
+    // 이전 실행의 창 위치/크기 복원 (기본 모니터를 맨 앞에 둠)
+    let saved_geometry = state_path
+        .as_deref()
+        .and_then(WindowGeometry::load)
+        .map(|geometry| {
+            let monitors: Vec<_> = event_loop
+                .primary_monitor()
+                .into_iter()
+                .chain(event_loop.available_monitors())
+                .map(|monitor| (monitor.position(), monitor.size()))
+                .collect();
+            geometry.clamp_to_monitors(&monitors)
+        });
+    if let Some(geometry) = saved_geometry {
+        builder = builder
+            .with_inner_size(geometry.size)
+            .with_maximized(geometry.maximized);
+        if let Some(position) = geometry.position {
+            builder = builder.with_position(position);
+        }
+    }
+
+    let window = builder.build(&event_loop).unwrap();
+
+    // 최대화된 채로 닫혀도 일반 크기를 잃지 않도록 마지막 일반 위치/크기를 추적
+    let mut geometry = saved_geometry.unwrap_or_else(|| WindowGeometry::from_window(&window));
 
     // 네이티브에서는 winit window를 사용해서 renderer 생성
-    let mut renderer = block_on(create_renderer(&window)).unwrap();
+    // 지정한 어댑터로 만들 수 없으면 (예: 이 창에 출력할 수 없는 백엔드) 자동 선택으로 재시도
+    let selected_renderer = adapter.and_then(|adapter| {
+        block_on(create_renderer_with_adapter(
+            &instance,
+            &window,
+            Some(adapter),
+        ))
+        .inspect_err(|e| {
+            log::warn!("Selected adapter failed ({e}), falling back to automatic selection")
+        })
+        .ok()
+    });
+    let mut renderer = match selected_renderer {
+        Some(renderer) => renderer,
+        None => block_on(create_renderer_with_adapter(&instance, &window, None)).unwrap(),
+    };
+    log::info!("Using adapter: {}", renderer.adapter_info().name);
+    let window = &window;
 
     let _ = event_loop.run(move |event, target| match event {
         Event::WindowEvent {
             event: WindowEvent::CloseRequested,
             ..
         } => {
+            geometry.update_from_window(window);
+            if let Some(path) = &state_path
+                && let Err(e) = geometry.save(path)
+            {
+                log::warn!("Failed to save window state: {e}");
+            }
             target.exit();
         }
         Event::WindowEvent {
             event: WindowEvent::Resized(new_size),
             ..
         } => {
+            geometry.update_from_window(window);
             renderer.resize(new_size);
         }
+        Event::WindowEvent {
+            event: WindowEvent::Moved(_),
+            ..
+        } => {
+            geometry.update_from_window(window);
+        }
+        Event::WindowEvent {
+            event:
+                WindowEvent::KeyboardInput {
+                    event:
+                        KeyEvent {
+                            physical_key: PhysicalKey::Code(KeyCode::KeyP),
+                            state: ElementState::Pressed,
+                            repeat: false,
+                            ..
+                        },
+                    ..
+                },
+            ..
+        } => {
+            // P 키로 폴리곤 모드(채움/와이어프레임/뒷면 컬링) 순환
+            let mode = renderer.set_polygon_mode(renderer.polygon_mode().next());
+            log::info!("Polygon mode: {mode:?}");
+            window.request_redraw();
+        }
         Event::WindowEvent {
             event: WindowEvent::RedrawRequested,
             ..
@@ -117,7 +399,8 @@ impl SurfaceProvider for web_sys::HtmlCanvasElement {
     ) -> Result<(Option<Surface<'static>>, PhysicalSize<u32>), Box<dyn std::error::Error>> {
         // wasm32에서는 캔버스를 직접 사용 (OffscreenCanvas 대신)
         let surface = instance.create_surface(wgpu::SurfaceTarget::Canvas(self.clone()))?;
-        let static_surface = unsafe { std::mem::transmute(surface) };
+        let static_surface =
+            unsafe { std::mem::transmute::<Surface<'_>, Surface<'static>>(surface) };
 
         let size = PhysicalSize::new(self.width(), self.height());
         Ok((Some(static_surface), size))
@@ -139,3 +422,126 @@ impl SurfaceProvider for HeadlessProvider {
         Ok((None, size))
     }
 }
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use super::*;
+
+    fn geometry(x: i32, y: i32, width: u32, height: u32) -> WindowGeometry {
+        WindowGeometry {
+            position: Some(PhysicalPosition::new(x, y)),
+            size: PhysicalSize::new(width, height),
+            maximized: false,
+        }
+    }
+
+    fn monitor(
+        x: i32,
+        y: i32,
+        width: u32,
+        height: u32,
+    ) -> (PhysicalPosition<i32>, PhysicalSize<u32>) {
+        (
+            PhysicalPosition::new(x, y),
+            PhysicalSize::new(width, height),
+        )
+    }
+
+    #[test]
+    fn visible_window_is_kept() {
+        let monitors = [monitor(0, 0, 1920, 1080), monitor(1920, 0, 1280, 1024)];
+        let on_second = geometry(2000, 100, 800, 600);
+        assert_eq!(on_second.clamp_to_monitors(&monitors), on_second);
+
+        // 일부만 걸쳐 있어도 충분히 보이면 그대로 둠
+        let partly_off = geometry(-400, 200, 800, 600);
+        assert_eq!(partly_off.clamp_to_monitors(&monitors), partly_off);
+    }
+
+    #[test]
+    fn off_screen_window_moves_to_primary_monitor() {
+        let monitors = [monitor(0, 0, 1920, 1080)];
+        let clamped = geometry(5000, -3000, 800, 600).clamp_to_monitors(&monitors);
+        assert_eq!(clamped, geometry(560, 240, 800, 600));
+
+        // 가장자리에 몇 픽셀만 걸친 경우도 보이지 않는 것으로 처리
+        let sliver = geometry(1910, 100, 800, 600).clamp_to_monitors(&monitors);
+        assert_eq!(sliver, geometry(560, 240, 800, 600));
+    }
+
+    #[test]
+    fn oversized_off_screen_window_shrinks_to_monitor() {
+        let monitors = [monitor(-1280, 0, 1280, 720)];
+        let clamped = geometry(4000, 0, 2560, 1440).clamp_to_monitors(&monitors);
+        assert_eq!(clamped, geometry(-1280, 0, 1280, 720));
+    }
+
+    #[test]
+    fn clamp_without_monitors_or_position_is_noop() {
+        let saved = geometry(5000, 5000, 800, 600);
+        assert_eq!(saved.clamp_to_monitors(&[]), saved);
+
+        let unknown = WindowGeometry {
+            position: None,
+            ..saved
+        };
+        assert_eq!(
+            unknown.clamp_to_monitors(&[monitor(0, 0, 1920, 1080)]),
+            unknown
+        );
+    }
+
+    #[test]
+    fn geometry_round_trips_through_file_format() {
+        let saved = WindowGeometry {
+            maximized: true,
+            ..geometry(-120, 45, 1024, 768)
+        };
+        assert_eq!(WindowGeometry::parse(&saved.serialize()), Some(saved));
+
+        let unknown = WindowGeometry {
+            position: None,
+            ..saved
+        };
+        assert_eq!(WindowGeometry::parse(&unknown.serialize()), Some(unknown));
+    }
+
+    #[test]
+    fn maximized_window_keeps_normal_geometry() {
+        let mut tracked = geometry(100, 80, 1024, 768);
+
+        // 최대화되면 화면 전체 크기가 들어오지만 일반 위치/크기는 그대로 유지
+        tracked.apply(
+            Some(PhysicalPosition::new(0, 0)),
+            PhysicalSize::new(1920, 1080),
+            true,
+        );
+        assert_eq!(
+            tracked,
+            WindowGeometry {
+                maximized: true,
+                ..geometry(100, 80, 1024, 768)
+            }
+        );
+
+        let restored = WindowGeometry::parse(&tracked.serialize()).unwrap();
+        assert!(restored.maximized);
+        assert_eq!(restored.position, Some(PhysicalPosition::new(100, 80)));
+        assert_eq!(restored.size, PhysicalSize::new(1024, 768));
+
+        // 최대화를 해제하면 다시 창 상태를 따라감
+        tracked.apply(
+            Some(PhysicalPosition::new(200, 150)),
+            PhysicalSize::new(800, 600),
+            false,
+        );
+        assert_eq!(tracked, geometry(200, 150, 800, 600));
+    }
+
+    #[test]
+    fn invalid_file_is_ignored() {
+        assert_eq!(WindowGeometry::parse(""), None);
+        assert_eq!(WindowGeometry::parse("width=abc\nheight=600"), None);
+        assert_eq!(WindowGeometry::parse("width=0\nheight=600"), None);
+    }
+}